@@ -0,0 +1,177 @@
+use std::time::UNIX_EPOCH;
+
+use pyo3::prelude::*;
+
+use crate::path_buf_to_pathlib_path;
+
+/// Thin wrapper around `std::fs::FileType`, exposed to Python so callers can
+/// branch on directory/file/symlink without a second `stat()` from Python.
+#[pyclass]
+pub struct FileType(std::fs::FileType);
+
+#[pymethods]
+impl FileType {
+    fn is_dir(&self) -> bool {
+        self.0.is_dir()
+    }
+
+    fn is_file(&self) -> bool {
+        self.0.is_file()
+    }
+
+    fn is_symlink(&self) -> bool {
+        self.0.is_symlink()
+    }
+}
+
+/// Thin wrapper around `std::fs::Metadata`, exposing the fields callers
+/// usually need (size, mtime) without a second `stat()` from Python.
+#[pyclass]
+pub struct Metadata(std::fs::Metadata);
+
+#[pymethods]
+impl Metadata {
+    fn size(&self) -> u64 {
+        self.0.len()
+    }
+
+    fn mtime(&self) -> PyResult<Option<f64>> {
+        match self.0.modified() {
+            Ok(time) => match time.duration_since(UNIX_EPOCH) {
+                Ok(duration) => Ok(Some(duration.as_secs_f64())),
+                Err(err) => Err(PyErr::new::<pyo3::exceptions::PyOSError, _>(format!(
+                    "{}",
+                    err
+                ))),
+            },
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// A richer alternative to the bare `pathlib.Path` entries `Walker` yields by
+/// default, exposing what `ignore::DirEntry` already knows so callers don't
+/// need a second `stat()` to branch on directory-vs-file or file size.
+///
+/// Opt in with `yield_entries=True`.
+#[pyclass]
+pub struct DirEntry(ignore::DirEntry);
+
+impl DirEntry {
+    pub fn new(entry: ignore::DirEntry) -> Self {
+        DirEntry(entry)
+    }
+}
+
+#[pymethods]
+impl DirEntry {
+    #[getter]
+    fn path(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        path_buf_to_pathlib_path(py, self.0.path().to_path_buf())
+    }
+
+    fn depth(&self) -> usize {
+        self.0.depth()
+    }
+
+    fn is_symlink(&self) -> bool {
+        self.0.path_is_symlink()
+    }
+
+    fn file_type(&self) -> Option<FileType> {
+        self.0.file_type().map(FileType)
+    }
+
+    fn metadata(&self) -> PyResult<Metadata> {
+        self.0
+            .metadata()
+            .map(Metadata)
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyOSError, _>(format!("{}", err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rignore-test-entry-{label}-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ))
+    }
+
+    fn entry_for(dir: &Path, path: &Path) -> ignore::DirEntry {
+        ignore::WalkBuilder::new(dir)
+            .build()
+            .filter_map(Result::ok)
+            .find(|entry| entry.path() == path)
+            .unwrap_or_else(|| panic!("no walked entry for {path:?}"))
+    }
+
+    #[test]
+    fn depth_and_file_type_reflect_the_underlying_entry() {
+        let dir = unique_temp_dir("depth-and-file-type");
+        let nested = dir.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("file.txt"), "hello").unwrap();
+
+        let root_entry = DirEntry::new(entry_for(&dir, &dir));
+        assert_eq!(root_entry.depth(), 0);
+        assert!(root_entry.file_type().unwrap().is_dir());
+        assert!(!root_entry.is_symlink());
+
+        let nested_entry = DirEntry::new(entry_for(&dir, &nested));
+        assert_eq!(nested_entry.depth(), 1);
+        assert!(nested_entry.file_type().unwrap().is_dir());
+
+        let file_entry = DirEntry::new(entry_for(&dir, &nested.join("file.txt")));
+        assert_eq!(file_entry.depth(), 2);
+        assert!(file_entry.file_type().unwrap().is_file());
+        assert!(!file_entry.is_symlink());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn metadata_reports_the_real_file_size_and_mtime() {
+        let dir = unique_temp_dir("metadata");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("data.txt"), "0123456789").unwrap();
+
+        let file_entry = DirEntry::new(entry_for(&dir, &dir.join("data.txt")));
+
+        let metadata = file_entry.metadata().unwrap();
+        assert_eq!(metadata.size(), 10);
+        assert!(metadata.mtime().unwrap().is_some());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn path_getter_returns_a_pathlib_path_matching_the_entry() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("path-getter");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("data.txt"), "").unwrap();
+
+        let expected = dir.join("data.txt");
+        let file_entry = DirEntry::new(entry_for(&dir, &expected));
+
+        Python::attach(|py| {
+            let path_obj = file_entry.path(py).unwrap();
+            let path_str: String = path_obj
+                .call_method0(py, "__str__")
+                .unwrap()
+                .extract(py)
+                .unwrap();
+            assert_eq!(PathBuf::from(path_str), expected);
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,218 @@
+use std::path::PathBuf;
+
+use ignore::gitignore::{Gitignore as IgnoreGitignore, GitignoreBuilder};
+use ignore::Match as IgnoreMatch;
+use pyo3::prelude::*;
+
+use crate::path_buf_to_pathlib_path;
+
+/// Which of the three outcomes `ignore::Match` distinguishes a path fell
+/// into: not matched by any pattern, matched by an ignore pattern, or
+/// matched by a later whitelist (`!pattern`) pattern overriding an earlier
+/// ignore.
+#[pyclass(eq, eq_int)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum MatchKind {
+    None,
+    Ignore,
+    Whitelist,
+}
+
+/// The outcome of testing a path against a `Gitignore`, along with enough
+/// detail about the pattern that decided it (its source file and line
+/// number) for callers to explain *why* a path was ignored.
+#[pyclass]
+#[derive(Clone)]
+pub struct Match {
+    #[pyo3(get)]
+    kind: MatchKind,
+    line_number: Option<u64>,
+    pattern: Option<String>,
+    source: Option<PathBuf>,
+}
+
+#[pymethods]
+impl Match {
+    #[getter]
+    fn line_number(&self) -> Option<u64> {
+        self.line_number
+    }
+
+    #[getter]
+    fn pattern(&self) -> Option<String> {
+        self.pattern.clone()
+    }
+
+    #[getter]
+    fn source(&self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        self.source
+            .clone()
+            .map(|path| path_buf_to_pathlib_path(py, path))
+            .transpose()
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self.kind, MatchKind::None)
+    }
+
+    fn is_ignore(&self) -> bool {
+        matches!(self.kind, MatchKind::Ignore)
+    }
+
+    fn is_whitelist(&self) -> bool {
+        matches!(self.kind, MatchKind::Whitelist)
+    }
+}
+
+fn match_from_ignore(m: IgnoreMatch<&ignore::gitignore::Glob>) -> Match {
+    match m {
+        IgnoreMatch::None => Match {
+            kind: MatchKind::None,
+            line_number: None,
+            pattern: None,
+            source: None,
+        },
+        IgnoreMatch::Ignore(glob) => Match {
+            kind: MatchKind::Ignore,
+            line_number: glob.line_number(),
+            pattern: Some(glob.original().to_string()),
+            source: glob.from().map(|path| path.to_path_buf()),
+        },
+        IgnoreMatch::Whitelist(glob) => Match {
+            kind: MatchKind::Whitelist,
+            line_number: glob.line_number(),
+            pattern: Some(glob.original().to_string()),
+            source: glob.from().map(|path| path.to_path_buf()),
+        },
+    }
+}
+
+/// A standalone gitignore matcher, for testing whether paths would be
+/// ignored without performing a directory walk.
+#[pyclass]
+pub struct Gitignore(IgnoreGitignore);
+
+#[pymethods]
+impl Gitignore {
+    #[new]
+    #[pyo3(signature = (root, patterns=None, files=None))]
+    fn new(
+        root: PathBuf,
+        patterns: Option<Vec<String>>,
+        files: Option<Vec<PathBuf>>,
+    ) -> PyResult<Self> {
+        let mut builder = GitignoreBuilder::new(&root);
+
+        if let Some(files) = files {
+            for file in files {
+                if let Some(err) = builder.add(file) {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "{}",
+                        err
+                    )));
+                }
+            }
+        }
+
+        if let Some(patterns) = patterns {
+            for pattern in patterns {
+                builder.add_line(None, &pattern).map_err(|err| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", err))
+                })?;
+            }
+        }
+
+        let gitignore = builder
+            .build()
+            .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", err)))?;
+
+        Ok(Gitignore(gitignore))
+    }
+
+    /// Tests `path` against the patterns directly, without considering
+    /// parent directories.
+    fn matched(&self, path: PathBuf, is_dir: bool) -> Match {
+        match_from_ignore(self.0.matched(&path, is_dir))
+    }
+
+    /// Like `matched`, but also walks up from `path` to the gitignore root,
+    /// so a file is correctly reported as ignored when one of its parent
+    /// directories is ignored.
+    fn matched_path_or_any_parents(&self, path: PathBuf, is_dir: bool) -> Match {
+        match_from_ignore(self.0.matched_path_or_any_parents(&path, is_dir))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matched_ignores_a_direct_pattern() {
+        let gitignore = Gitignore::new(
+            PathBuf::from("/repo"),
+            Some(vec!["*.log".to_string()]),
+            None,
+        )
+        .unwrap();
+
+        let result = gitignore.matched(PathBuf::from("/repo/debug.log"), false);
+        assert!(result.is_ignore());
+    }
+
+    #[test]
+    fn matched_does_not_consider_parent_directories() {
+        let gitignore = Gitignore::new(
+            PathBuf::from("/repo"),
+            Some(vec!["target/".to_string()]),
+            None,
+        )
+        .unwrap();
+
+        // `matched` only tests the path itself, so a file inside an ignored
+        // directory isn't reported as ignored unless we walk up to find it.
+        let result = gitignore.matched(PathBuf::from("/repo/target/debug/build.rs"), false);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn matched_path_or_any_parents_finds_an_ignored_ancestor() {
+        let gitignore = Gitignore::new(
+            PathBuf::from("/repo"),
+            Some(vec!["target/".to_string()]),
+            None,
+        )
+        .unwrap();
+
+        let result = gitignore
+            .matched_path_or_any_parents(PathBuf::from("/repo/target/debug/build.rs"), false);
+        assert!(result.is_ignore());
+    }
+
+    #[test]
+    fn whitelist_pattern_overrides_an_earlier_ignore() {
+        let gitignore = Gitignore::new(
+            PathBuf::from("/repo"),
+            Some(vec!["*.log".to_string(), "!keep.log".to_string()]),
+            None,
+        )
+        .unwrap();
+
+        let result = gitignore.matched_path_or_any_parents(PathBuf::from("/repo/keep.log"), false);
+        assert!(result.is_whitelist());
+    }
+
+    #[test]
+    fn unmatched_path_reports_none() {
+        let gitignore = Gitignore::new(
+            PathBuf::from("/repo"),
+            Some(vec!["*.log".to_string()]),
+            None,
+        )
+        .unwrap();
+
+        let result =
+            gitignore.matched_path_or_any_parents(PathBuf::from("/repo/src/main.rs"), false);
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use regex::RegexSet;
+
+const HGIGNORE_FILENAME: &str = ".hgignore";
+
+#[derive(Clone, Copy)]
+enum Syntax {
+    // Mercurial's default `.hgignore` syntax: each line is a regex matched
+    // anywhere in the path.
+    Regexp,
+    Glob,
+}
+
+/// The patterns found in a single `.hgignore` file, compiled relative to the
+/// directory that contains it.
+struct CompiledHgIgnore {
+    dir: PathBuf,
+    globs: Option<GlobSet>,
+    regexes: Option<RegexSet>,
+}
+
+impl CompiledHgIgnore {
+    fn parse(dir: &Path, content: &str) -> Self {
+        // `.hgignore` defaults to regex syntax; a `syntax: glob` or
+        // `syntax: regexp` line switches the mode for the lines that follow.
+        let mut syntax = Syntax::Regexp;
+        let mut glob_builder = GlobSetBuilder::new();
+        let mut has_globs = false;
+        let mut regex_patterns = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(value) = line.strip_prefix("syntax:") {
+                syntax = match value.trim() {
+                    "glob" => Syntax::Glob,
+                    _ => Syntax::Regexp,
+                };
+                continue;
+            }
+
+            match syntax {
+                Syntax::Glob => {
+                    if let Ok(glob) = GlobBuilder::new(line).literal_separator(false).build() {
+                        glob_builder.add(glob);
+                        has_globs = true;
+                    }
+                }
+                Syntax::Regexp => {
+                    // Validate each line on its own so one malformed regex
+                    // doesn't take the whole file's `RegexSet` down with it
+                    // (`RegexSet::new` fails the entire set if any pattern
+                    // in it is invalid).
+                    if regex::Regex::new(line).is_ok() {
+                        regex_patterns.push(line.to_string());
+                    }
+                }
+            }
+        }
+
+        let globs = if has_globs {
+            glob_builder.build().ok()
+        } else {
+            None
+        };
+        let regexes = if regex_patterns.is_empty() {
+            None
+        } else {
+            RegexSet::new(&regex_patterns).ok()
+        };
+
+        CompiledHgIgnore {
+            dir: dir.to_path_buf(),
+            globs,
+            regexes,
+        }
+    }
+
+    fn is_match(&self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(&self.dir) else {
+            return false;
+        };
+        let relative = relative.to_string_lossy();
+
+        if let Some(globs) = &self.globs {
+            if globs.is_match(relative.as_ref()) {
+                return true;
+            }
+        }
+
+        if let Some(regexes) = &self.regexes {
+            if regexes.is_match(relative.as_ref()) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Matches paths against the `.hgignore` files found in their ancestor
+/// directories, nearest first: a path is ignored when any of those files
+/// matches it, not only the nearest one. This mirrors how `WalkBuilder`
+/// layers `.gitignore`/`.ignore` files.
+///
+/// `.hgignore` files are discovered lazily, one directory at a time, as
+/// `is_ignored` is called for paths under them, and the result is cached per
+/// directory - there is no upfront recursive scan of the tree, so this stays
+/// bounded by the same directories the walk itself actually visits instead of
+/// duplicating the walk's own I/O ahead of time.
+///
+/// The ancestor search is bounded by `root`, the directory the walk started
+/// from, unless `read_parents_ignores` opts into climbing past it - the same
+/// switch `WalkBuilder::parents` gives the git matchers in this same
+/// builder. Without that bound, a `.hgignore` sitting in some unrelated
+/// ancestor directory (`/home/user` when walking `/home/user/project`)
+/// would silently apply to a walk that has nothing to do with it.
+pub struct HgIgnoreSet {
+    root: PathBuf,
+    read_parents_ignores: bool,
+    cache: Mutex<HashMap<PathBuf, Option<Arc<CompiledHgIgnore>>>>,
+}
+
+impl HgIgnoreSet {
+    pub fn new(root: PathBuf, read_parents_ignores: bool) -> Self {
+        HgIgnoreSet {
+            root,
+            read_parents_ignores,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn matcher_for_dir(&self, dir: &Path) -> Option<Arc<CompiledHgIgnore>> {
+        if let Some(cached) = self.cache.lock().unwrap().get(dir) {
+            return cached.clone();
+        }
+
+        let matcher = fs::read_to_string(dir.join(HGIGNORE_FILENAME))
+            .ok()
+            .map(|content| Arc::new(CompiledHgIgnore::parse(dir, &content)));
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), matcher.clone());
+
+        matcher
+    }
+
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        // Only directories can contain a `.hgignore`, so start the ancestor
+        // search at `path` itself when it's a directory, and at its parent
+        // otherwise - a file can never be the directory a `.hgignore` lives
+        // in, and probing it as one would just be a guaranteed-failing
+        // `read_to_string` cached under that file's own unique path.
+        let start = if is_dir { Some(path) } else { path.parent() };
+        let Some(start) = start else {
+            return false;
+        };
+
+        // Layer ancestor `.hgignore`s the same way `WalkBuilder` layers
+        // `.gitignore`/`.ignore` files: a closer file only overrides a more
+        // distant one when it actually matches, so the absence of a match in
+        // the nearest file must still fall through to the next ancestor
+        // rather than short-circuiting the search.
+        for dir in start.ancestors() {
+            if let Some(matcher) = self.matcher_for_dir(dir) {
+                if matcher.is_match(path) {
+                    return true;
+                }
+            }
+
+            // Don't climb past the walk root into unrelated ancestor
+            // directories unless the caller opted in, same as
+            // `WalkBuilder::parents` gates git's own parent-ignore lookup.
+            if dir == self.root && !self.read_parents_ignores {
+                break;
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rignore-test-hgignore-{label}-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ))
+    }
+
+    #[test]
+    fn regexp_is_the_default_syntax() {
+        let dir = Path::new("/repo");
+        let hgignore = CompiledHgIgnore::parse(dir, "build\\.rs$\n");
+
+        assert!(hgignore.is_match(Path::new("/repo/src/build.rs")));
+        assert!(!hgignore.is_match(Path::new("/repo/src/build.rs.bak")));
+    }
+
+    #[test]
+    fn syntax_header_switches_to_glob_for_following_lines() {
+        let dir = Path::new("/repo");
+        let hgignore = CompiledHgIgnore::parse(dir, "syntax: glob\n*.log\n");
+
+        assert!(hgignore.is_match(Path::new("/repo/debug.log")));
+        assert!(!hgignore.is_match(Path::new("/repo/debug.rs")));
+    }
+
+    #[test]
+    fn syntax_header_can_switch_back_to_regexp() {
+        let dir = Path::new("/repo");
+        let hgignore = CompiledHgIgnore::parse(dir, "syntax: glob\n*.log\nsyntax: regexp\n^out/\n");
+
+        assert!(hgignore.is_match(Path::new("/repo/debug.log")));
+        assert!(hgignore.is_match(Path::new("/repo/out/anything")));
+    }
+
+    #[test]
+    fn blank_lines_and_comments_are_ignored() {
+        let dir = Path::new("/repo");
+        let hgignore = CompiledHgIgnore::parse(dir, "\n# a comment\nsyntax: glob\n\n*.tmp\n");
+
+        assert!(hgignore.is_match(Path::new("/repo/file.tmp")));
+    }
+
+    #[test]
+    fn is_ignored_falls_through_to_a_more_distant_ancestor_hgignore() {
+        let dir = unique_temp_dir("nearest-ancestor");
+        let nested = dir.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(dir.join(HGIGNORE_FILENAME), "syntax: glob\n*.log\n").unwrap();
+        std::fs::write(nested.join(HGIGNORE_FILENAME), "syntax: glob\n*.tmp\n").unwrap();
+
+        let set = HgIgnoreSet::new(dir.clone(), true);
+
+        // The nested .hgignore doesn't mention .log files, so the search
+        // must fall through to the root .hgignore rather than stopping at
+        // the nearest file just because it exists.
+        assert!(set.is_ignored(&nested.join("debug.log"), false));
+        // Matched directly by the nested .hgignore.
+        assert!(set.is_ignored(&nested.join("debug.tmp"), false));
+        // Directly under the root, only the root .hgignore applies.
+        assert!(set.is_ignored(&dir.join("debug.log"), false));
+        assert!(!set.is_ignored(&dir.join("debug.tmp"), false));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn is_ignored_does_not_climb_past_root_unless_parents_ignores_is_enabled() {
+        let outer = unique_temp_dir("bounded-root-outer");
+        let root = outer.join("root");
+        std::fs::create_dir_all(&root).unwrap();
+        // This .hgignore sits outside the walk root, in a directory the
+        // walk has nothing to do with.
+        std::fs::write(outer.join(HGIGNORE_FILENAME), "syntax: glob\n*.log\n").unwrap();
+
+        let bounded = HgIgnoreSet::new(root.clone(), false);
+        assert!(
+            !bounded.is_ignored(&root.join("debug.log"), false),
+            "an .hgignore above the walk root must not apply unless parent ignores are enabled"
+        );
+
+        let unbounded = HgIgnoreSet::new(root.clone(), true);
+        assert!(
+            unbounded.is_ignored(&root.join("debug.log"), false),
+            "enabling parent ignores should still climb past the walk root, like WalkBuilder::parents does for git"
+        );
+
+        std::fs::remove_dir_all(&outer).ok();
+    }
+
+    #[test]
+    fn is_ignored_caches_directories_without_an_hgignore() {
+        let dir = unique_temp_dir("no-hgignore");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let set = HgIgnoreSet::new(dir.clone(), true);
+        assert!(!set.is_ignored(&dir.join("anything"), false));
+        // A second lookup should hit the cache rather than re-reading disk;
+        // behaviourally this should simply remain consistent.
+        assert!(!set.is_ignored(&dir.join("anything"), false));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn is_ignored_caches_per_directory_not_per_file() {
+        let dir = unique_temp_dir("per-directory-cache");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(HGIGNORE_FILENAME), "syntax: glob\n*.log\n").unwrap();
+
+        let set = HgIgnoreSet::new(dir.clone(), true);
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            set.is_ignored(&dir.join(name), false);
+        }
+
+        // Every file above lives in the same directory, so the cache must
+        // only gain one entry per directory probed (`dir` and, with parent
+        // ignores enabled, each of its ancestors) - not one per file checked.
+        assert_eq!(set.cache.lock().unwrap().len(), dir.ancestors().count());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
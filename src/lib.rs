@@ -2,8 +2,59 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use ignore::overrides::OverrideBuilder;
+use ignore::types::TypesBuilder;
 use pyo3::prelude::*;
 
+mod entry;
+mod gitignore;
+mod hgignore;
+mod parallel;
+
+/// Builds an `ignore::types::Types` matcher from the `types`/`types_not`
+/// selectors plus any custom type definitions, loading the crate's built-in
+/// language definitions first so names like `"py"` or `"rust"` resolve.
+fn build_types(
+    types: Option<Vec<String>>,
+    types_not: Option<Vec<String>>,
+    add_type_definitions: Option<Vec<(String, Vec<String>)>>,
+) -> PyResult<Option<ignore::types::Types>> {
+    if types.is_none() && types_not.is_none() && add_type_definitions.is_none() {
+        return Ok(None);
+    }
+
+    let mut builder = TypesBuilder::new();
+    builder.add_defaults();
+
+    if let Some(definitions) = add_type_definitions {
+        for (name, globs) in definitions {
+            for glob in globs {
+                builder.add(&name, &glob).map_err(|err| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", err))
+                })?;
+            }
+        }
+    }
+
+    if let Some(types) = types {
+        for name in types {
+            builder.select(&name);
+        }
+    }
+
+    if let Some(types_not) = types_not {
+        for name in types_not {
+            builder.negate(&name);
+        }
+    }
+
+    // Unknown type names are only reported here, by `build()`.
+    let types = builder
+        .build()
+        .map_err(|err| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("{}", err)))?;
+
+    Ok(Some(types))
+}
+
 fn path_buf_to_pathlib_path(py: Python, path_buf: PathBuf) -> PyResult<Py<PyAny>> {
     let path_str = path_buf
         .to_str()
@@ -16,8 +67,229 @@ fn path_buf_to_pathlib_path(py: Python, path_buf: PathBuf) -> PyResult<Py<PyAny>
     Ok(pathlib_path.unbind())
 }
 
+/// Builds an `ignore::WalkBuilder` from the options shared by every entry
+/// point (the single-threaded `Walker`, `walk`, and the parallel walkers),
+/// so the option-wiring logic only has to live in one place.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn build_walk_builder(
+    path: &PathBuf,
+
+    ignore_hidden: Option<bool>,
+
+    read_ignore_files: Option<bool>,
+    read_parents_ignores: Option<bool>,
+
+    read_git_ignore: Option<bool>,
+    read_global_git_ignore: Option<bool>,
+    read_git_exclude: Option<bool>,
+    require_git: Option<bool>,
+
+    read_hg_ignore: Option<bool>,
+
+    additional_ignores: Option<Vec<String>>,
+    additional_ignore_paths: Option<Vec<String>>,
+    overrides: Option<Vec<String>>,
+
+    types: Option<Vec<String>>,
+    types_not: Option<Vec<String>>,
+    add_type_definitions: Option<Vec<(String, Vec<String>)>>,
+
+    max_depth: Option<usize>,
+    max_filesize: Option<u64>,
+
+    follow_links: Option<bool>,
+
+    case_insensitive: Option<bool>,
+    same_file_system: Option<bool>,
+
+    sort: Option<String>,
+    reverse: Option<bool>,
+    path_comparator: Option<Py<PyAny>>,
+
+    should_exclude_entry: Option<Py<PyAny>>,
+) -> PyResult<ignore::WalkBuilder> {
+    let mut builder = ignore::WalkBuilder::new(path);
+
+    // doing this at the beginning because otherwise it would override all the other options
+    if let Some(override_patterns) = overrides {
+        let mut override_builder = OverrideBuilder::new(path);
+        for pattern in override_patterns {
+            let _ = override_builder.add(&pattern);
+        }
+
+        if let Ok(overrides) = override_builder.build() {
+            builder.overrides(overrides);
+        }
+    }
+
+    if let Some(types) = build_types(types, types_not, add_type_definitions)? {
+        builder.types(types);
+    }
+
+    if let Some(ignore_hidden) = ignore_hidden {
+        builder.hidden(ignore_hidden);
+    }
+
+    if let Some(follow_links) = follow_links {
+        builder.follow_links(follow_links);
+    }
+
+    builder.max_depth(max_depth);
+    builder.max_filesize(max_filesize);
+
+    if let Some(read_ignore_files) = read_ignore_files {
+        builder.ignore(read_ignore_files);
+    }
+
+    if let Some(additional_ignores) = additional_ignores {
+        for pattern in additional_ignores {
+            builder.add_ignore(pattern);
+        }
+    }
+
+    if let Some(additional_ignore_paths) = additional_ignore_paths {
+        for path in additional_ignore_paths {
+            builder.add_custom_ignore_filename(path);
+        }
+    }
+
+    if let Some(read_parents_ignores) = read_parents_ignores {
+        builder.parents(read_parents_ignores);
+    }
+
+    if let Some(read_global_git_ignore) = read_global_git_ignore {
+        builder.git_global(read_global_git_ignore);
+    }
+
+    if let Some(read_git_ignore) = read_git_ignore {
+        builder.git_ignore(read_git_ignore);
+    }
+
+    if let Some(read_git_exclude) = read_git_exclude {
+        builder.git_exclude(read_git_exclude);
+    }
+
+    if let Some(require_git) = require_git {
+        builder.require_git(require_git);
+    }
+
+    if let Some(case_insensitive) = case_insensitive {
+        builder.ignore_case_insensitive(case_insensitive);
+    }
+
+    if let Some(same_file_system) = same_file_system {
+        builder.same_file_system(same_file_system);
+    }
+
+    if let Some(sort) = sort {
+        let reverse = reverse.unwrap_or(false);
+
+        match sort.as_str() {
+            "name" => {
+                builder.sort_by_file_name(move |a, b| {
+                    let ordering = a.cmp(b);
+                    if reverse {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                });
+            }
+            "path" => match path_comparator {
+                Some(comparator) => {
+                    builder.sort_by_file_path(move |a, b| {
+                        let ordering = match Python::attach(|py| -> PyResult<std::cmp::Ordering> {
+                            let path_a = path_buf_to_pathlib_path(py, a.to_path_buf())?;
+                            let path_b = path_buf_to_pathlib_path(py, b.to_path_buf())?;
+                            let result: i32 =
+                                comparator.call1(py, (path_a, path_b))?.extract(py)?;
+                            Ok(result.cmp(&0))
+                        }) {
+                            Ok(ordering) => ordering,
+                            Err(e) => {
+                                eprintln!("Error in path_comparator function: {:?}", e);
+                                std::cmp::Ordering::Equal
+                            }
+                        };
+
+                        if reverse {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    });
+                }
+                None => {
+                    builder.sort_by_file_path(move |a, b| {
+                        let ordering = a.cmp(b);
+                        if reverse {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    });
+                }
+            },
+            other => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "invalid sort option: {:?}, expected \"name\" or \"path\"",
+                    other
+                )));
+            }
+        }
+    }
+
+    let hg_ignore = if read_hg_ignore.unwrap_or(false) {
+        Some(Arc::new(hgignore::HgIgnoreSet::new(
+            path.clone(),
+            read_parents_ignores.unwrap_or(true),
+        )))
+    } else {
+        None
+    };
+
+    if hg_ignore.is_some() || should_exclude_entry.is_some() {
+        let python_filter = should_exclude_entry.map(|filter_func| {
+            Arc::new(move |entry: &ignore::DirEntry| -> PyResult<bool> {
+                Python::attach(|py| {
+                    let path_buf = entry.path().to_path_buf();
+                    let pathlib_path = path_buf_to_pathlib_path(py, path_buf)?;
+                    let args = (pathlib_path,);
+                    filter_func.call1(py, args)?.extract(py)
+                })
+            })
+        });
+
+        builder.filter_entry(move |entry| {
+            if let Some(hg_ignore) = &hg_ignore {
+                let is_dir = entry.file_type().is_some_and(|ft| ft.is_dir());
+                if hg_ignore.is_ignored(entry.path(), is_dir) {
+                    return false;
+                }
+            }
+
+            match &python_filter {
+                Some(filter) => match filter(entry) {
+                    Ok(result) => !result,
+                    Err(e) => {
+                        // Log the error or handle it as appropriate for your application
+                        eprintln!("Error in filter function: {:?}", e);
+                        false // Exclude the entry if there's an error
+                    }
+                },
+                None => true,
+            }
+        });
+    }
+
+    Ok(builder)
+}
+
 #[pyclass]
-pub struct Walker(ignore::Walk);
+pub struct Walker {
+    walk: ignore::Walk,
+    yield_entries: bool,
+}
 
 #[pymethods]
 impl Walker {
@@ -31,16 +303,25 @@ impl Walker {
         read_global_git_ignore=None,
         read_git_exclude=None,
         require_git=None,
+        read_hg_ignore=None,
         additional_ignores=None,
         additional_ignore_paths=None,
         overrides=None,
+        types=None,
+        types_not=None,
+        add_type_definitions=None,
         max_depth=None,
         max_filesize=None,
         follow_links=None,
         case_insensitive=None,
         same_file_system=None,
+        sort=None,
+        reverse=None,
+        path_comparator=None,
         should_exclude_entry=None,
+        yield_entries=None,
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         path: PathBuf,
 
@@ -54,10 +335,16 @@ impl Walker {
         read_git_exclude: Option<bool>,
         require_git: Option<bool>,
 
+        read_hg_ignore: Option<bool>,
+
         additional_ignores: Option<Vec<String>>,
         additional_ignore_paths: Option<Vec<String>>,
         overrides: Option<Vec<String>>,
 
+        types: Option<Vec<String>>,
+        types_not: Option<Vec<String>>,
+        add_type_definitions: Option<Vec<(String, Vec<String>)>>,
+
         max_depth: Option<usize>,
         max_filesize: Option<u64>,
 
@@ -65,103 +352,45 @@ impl Walker {
 
         case_insensitive: Option<bool>,
         same_file_system: Option<bool>,
-        should_exclude_entry: Option<Py<PyAny>>,
-    ) -> Self {
-        let mut builder = ignore::WalkBuilder::new(&path);
-
-        // doing this at the beginning because otherwise it would override all the other options
-        if let Some(override_patterns) = overrides {
-            let mut override_builder = OverrideBuilder::new(&path);
-            for pattern in override_patterns {
-                let _ = override_builder.add(&pattern);
-            }
-
-            if let Ok(overrides) = override_builder.build() {
-                builder.overrides(overrides);
-            }
-        }
-
-        if let Some(ignore_hidden) = ignore_hidden {
-            builder.hidden(ignore_hidden);
-        }
-
-        if let Some(follow_links) = follow_links {
-            builder.follow_links(follow_links);
-        }
-
-        builder.max_depth(max_depth);
-        builder.max_filesize(max_filesize);
-
-        if let Some(read_ignore_files) = read_ignore_files {
-            builder.ignore(read_ignore_files);
-        }
-
-        if let Some(additional_ignores) = additional_ignores {
-            for pattern in additional_ignores {
-                builder.add_ignore(pattern);
-            }
-        }
-
-        if let Some(additional_ignore_paths) = additional_ignore_paths {
-            for path in additional_ignore_paths {
-                builder.add_custom_ignore_filename(path);
-            }
-        }
-
-        if let Some(read_parents_ignores) = read_parents_ignores {
-            builder.parents(read_parents_ignores);
-        }
-
-        if let Some(read_global_git_ignore) = read_global_git_ignore {
-            builder.git_global(read_global_git_ignore);
-        }
-
-        if let Some(read_git_ignore) = read_git_ignore {
-            builder.git_ignore(read_git_ignore);
-        }
-
-        if let Some(read_git_exclude) = read_git_exclude {
-            builder.git_exclude(read_git_exclude);
-        }
-
-        if let Some(require_git) = require_git {
-            builder.require_git(require_git);
-        }
-
-        if let Some(case_insensitive) = case_insensitive {
-            builder.ignore_case_insensitive(case_insensitive);
-        }
-
-        if let Some(same_file_system) = same_file_system {
-            builder.same_file_system(same_file_system);
-        }
-
 
+        sort: Option<String>,
+        reverse: Option<bool>,
+        path_comparator: Option<Py<PyAny>>,
 
-        if let Some(filter_func) = should_exclude_entry {
-            let filter = Arc::new(move |entry: &ignore::DirEntry| -> PyResult<bool> {
-                Python::attach(|py| {
-                    let path_buf = entry.path().to_path_buf();
-                    let pathlib_path = path_buf_to_pathlib_path(py, path_buf)?;
-                    let args = (pathlib_path,);
-                    filter_func.call1(py, args)?.extract(py)
-                })
-            });
-
-            builder.filter_entry(move |entry| {
-                match filter(entry) {
-                    Ok(result) => !result,
-                    Err(e) => {
-                        // Log the error or handle it as appropriate for your application
-                        eprintln!("Error in filter function: {:?}", e);
-                        false // Exclude the entry if there's an error
-                    }
-                }
-            });
-        }
-
-
-        Walker(builder.build())
+        should_exclude_entry: Option<Py<PyAny>>,
+        yield_entries: Option<bool>,
+    ) -> PyResult<Self> {
+        let builder = build_walk_builder(
+            &path,
+            ignore_hidden,
+            read_ignore_files,
+            read_parents_ignores,
+            read_git_ignore,
+            read_global_git_ignore,
+            read_git_exclude,
+            require_git,
+            read_hg_ignore,
+            additional_ignores,
+            additional_ignore_paths,
+            overrides,
+            types,
+            types_not,
+            add_type_definitions,
+            max_depth,
+            max_filesize,
+            follow_links,
+            case_insensitive,
+            same_file_system,
+            sort,
+            reverse,
+            path_comparator,
+            should_exclude_entry,
+        )?;
+
+        Ok(Walker {
+            walk: builder.build(),
+            yield_entries: yield_entries.unwrap_or(false),
+        })
     }
 
     fn __iter__(slf: PyRef<Self>) -> PyResult<Py<Walker>> {
@@ -169,12 +398,17 @@ impl Walker {
     }
 
     fn __next__(mut slf: PyRefMut<Self>) -> PyResult<Option<Py<PyAny>>> {
-        match slf.0.next() {
+        match slf.walk.next() {
             Some(Ok(entry)) => {
-                let path_buf = entry.path().to_path_buf();
-                let pathlib_path = path_buf_to_pathlib_path(slf.py(), path_buf)?;
+                if slf.yield_entries {
+                    let dir_entry = Py::new(slf.py(), entry::DirEntry::new(entry))?;
+                    Ok(Some(dir_entry.into_any()))
+                } else {
+                    let path_buf = entry.path().to_path_buf();
+                    let pathlib_path = path_buf_to_pathlib_path(slf.py(), path_buf)?;
 
-                Ok(Some(pathlib_path))
+                    Ok(Some(pathlib_path))
+                }
             }
             Some(Err(err)) => Err(PyErr::new::<pyo3::exceptions::PyOSError, _>(format!(
                 "{}",
@@ -195,16 +429,25 @@ impl Walker {
     read_global_git_ignore=None,
     read_git_exclude=None,
     require_git=None,
+    read_hg_ignore=None,
     additional_ignores=None,
     additional_ignore_paths=None,
     overrides=None,
+    types=None,
+    types_not=None,
+    add_type_definitions=None,
     max_depth=None,
     max_filesize=None,
     follow_links=None,
     case_insensitive=None,
     same_file_system=None,
+    sort=None,
+    reverse=None,
+    path_comparator=None,
     should_exclude_entry=None,
+    yield_entries=None,
 ))]
+#[allow(clippy::too_many_arguments)]
 fn walk(
     path: PathBuf,
 
@@ -218,10 +461,16 @@ fn walk(
     read_git_exclude: Option<bool>,
     require_git: Option<bool>,
 
+    read_hg_ignore: Option<bool>,
+
     additional_ignores: Option<Vec<String>>,
     additional_ignore_paths: Option<Vec<String>>,
     overrides: Option<Vec<String>>,
 
+    types: Option<Vec<String>>,
+    types_not: Option<Vec<String>>,
+    add_type_definitions: Option<Vec<(String, Vec<String>)>>,
+
     max_depth: Option<usize>,
     max_filesize: Option<u64>,
 
@@ -230,9 +479,14 @@ fn walk(
     case_insensitive: Option<bool>,
     same_file_system: Option<bool>,
 
+    sort: Option<String>,
+    reverse: Option<bool>,
+    path_comparator: Option<Py<PyAny>>,
+
     should_exclude_entry: Option<Py<PyAny>>,
+    yield_entries: Option<bool>,
 ) -> PyResult<Walker> {
-    Ok(Walker::new(
+    Walker::new(
         path,
         ignore_hidden,
         read_ignore_files,
@@ -241,23 +495,238 @@ fn walk(
         read_global_git_ignore,
         read_git_exclude,
         require_git,
+        read_hg_ignore,
         additional_ignores,
         additional_ignore_paths,
         overrides,
+        types,
+        types_not,
+        add_type_definitions,
         max_depth,
         max_filesize,
         follow_links,
         case_insensitive,
         same_file_system,
+        sort,
+        reverse,
+        path_comparator,
         should_exclude_entry,
-    ))
+        yield_entries,
+    )
 }
 
 /// A Python module implemented in Rust.
 #[pymodule(gil_used = false)]
 fn rignore(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Walker>()?;
+    m.add_class::<entry::DirEntry>()?;
+    m.add_class::<entry::FileType>()?;
+    m.add_class::<entry::Metadata>()?;
+    m.add_class::<gitignore::Gitignore>()?;
+    m.add_class::<gitignore::Match>()?;
+    m.add_class::<gitignore::MatchKind>()?;
+    m.add_class::<parallel::WalkState>()?;
     m.add_function(wrap_pyfunction!(walk, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(parallel::walk_parallel, m)?)?;
+    m.add_function(wrap_pyfunction!(parallel::collect_parallel, m)?)?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selecting_a_known_type_builds_a_matcher_that_selects_it() {
+        let types = build_types(Some(vec!["py".to_string()]), None, None)
+            .unwrap()
+            .expect("selecting a type should produce a matcher, not None");
+
+        assert!(types.matched("main.py", false).is_whitelist());
+        assert!(types.matched("main.rs", false).is_ignore());
+    }
+
+    #[test]
+    fn selecting_an_unknown_type_is_a_value_error() {
+        pyo3::prepare_freethreaded_python();
+
+        let err = build_types(Some(vec!["not-a-real-type".to_string()]), None, None).expect_err(
+            "an unknown type name must be rejected, not silently build an empty matcher",
+        );
+
+        Python::attach(|py| {
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        });
+    }
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rignore-test-lib-{label}-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ))
+    }
+
+    fn sorted_file_names(
+        dir: &PathBuf,
+        sort: Option<String>,
+        reverse: Option<bool>,
+        path_comparator: Option<Py<PyAny>>,
+    ) -> Vec<String> {
+        let builder = build_walk_builder(
+            dir,
+            None, // ignore_hidden
+            None, // read_ignore_files
+            None, // read_parents_ignores
+            None, // read_git_ignore
+            None, // read_global_git_ignore
+            None, // read_git_exclude
+            None, // require_git
+            None, // read_hg_ignore
+            None, // additional_ignores
+            None, // additional_ignore_paths
+            None, // overrides
+            None, // types
+            None, // types_not
+            None, // add_type_definitions
+            None, // max_depth
+            None, // max_filesize
+            None, // follow_links
+            None, // case_insensitive
+            None, // same_file_system
+            sort,
+            reverse,
+            path_comparator,
+            None, // should_exclude_entry
+        )
+        .unwrap();
+
+        builder
+            .build()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_file()))
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn sort_by_file_name_orders_ascending_and_honors_reverse() {
+        let dir = unique_temp_dir("sort-name");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.txt"), "").unwrap();
+        std::fs::write(dir.join("a.txt"), "").unwrap();
+        std::fs::write(dir.join("c.txt"), "").unwrap();
+
+        assert_eq!(
+            sorted_file_names(&dir, Some("name".to_string()), None, None),
+            vec!["a.txt", "b.txt", "c.txt"]
+        );
+        assert_eq!(
+            sorted_file_names(&dir, Some("name".to_string()), Some(true), None),
+            vec!["c.txt", "b.txt", "a.txt"]
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn sort_by_file_path_orders_ascending_and_honors_reverse() {
+        let dir = unique_temp_dir("sort-path");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.txt"), "").unwrap();
+        std::fs::write(dir.join("a.txt"), "").unwrap();
+        std::fs::write(dir.join("c.txt"), "").unwrap();
+
+        assert_eq!(
+            sorted_file_names(&dir, Some("path".to_string()), None, None),
+            vec!["a.txt", "b.txt", "c.txt"]
+        );
+        assert_eq!(
+            sorted_file_names(&dir, Some("path".to_string()), Some(true), None),
+            vec!["c.txt", "b.txt", "a.txt"]
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[pyfunction]
+    fn reverse_str_path_comparator(py: Python<'_>, a: Py<PyAny>, b: Py<PyAny>) -> PyResult<i32> {
+        let a = a.bind(py).str()?.to_string();
+        let b = b.bind(py).str()?.to_string();
+
+        Ok(match b.cmp(&a) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        })
+    }
+
+    #[test]
+    fn sort_by_file_path_uses_the_custom_comparator_when_given() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("path-comparator");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "").unwrap();
+        std::fs::write(dir.join("b.txt"), "").unwrap();
+        std::fs::write(dir.join("c.txt"), "").unwrap();
+
+        Python::attach(|py| {
+            let comparator: Py<PyAny> = wrap_pyfunction!(reverse_str_path_comparator, py)
+                .unwrap()
+                .into_any()
+                .unbind();
+
+            assert_eq!(
+                sorted_file_names(&dir, Some("path".to_string()), None, Some(comparator)),
+                vec!["c.txt", "b.txt", "a.txt"],
+                "a comparator that reverses string order should override the default path order"
+            );
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn invalid_sort_option_is_a_value_error() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("sort-invalid");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let err = build_walk_builder(
+            &dir,
+            None, // ignore_hidden
+            None, // read_ignore_files
+            None, // read_parents_ignores
+            None, // read_git_ignore
+            None, // read_global_git_ignore
+            None, // read_git_exclude
+            None, // require_git
+            None, // read_hg_ignore
+            None, // additional_ignores
+            None, // additional_ignore_paths
+            None, // overrides
+            None, // types
+            None, // types_not
+            None, // add_type_definitions
+            None, // max_depth
+            None, // max_filesize
+            None, // follow_links
+            None, // case_insensitive
+            None, // same_file_system
+            Some("bogus".to_string()),
+            None, // reverse
+            None, // path_comparator
+            None, // should_exclude_entry
+        )
+        .expect_err("an unrecognized sort option must be rejected, not silently ignored");
+
+        Python::attach(|py| {
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
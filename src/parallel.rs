@@ -0,0 +1,647 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use ignore::WalkState as IgnoreWalkState;
+use pyo3::prelude::*;
+
+use crate::entry::DirEntry;
+use crate::{build_walk_builder, path_buf_to_pathlib_path};
+
+/// How a `walk_parallel` callback wants the traversal to proceed.
+///
+/// Entries are batched per-thread to amortize GIL reacquisition, so there is
+/// no meaningful per-entry `SKIP`: by the time a batch reaches the callback,
+/// the directories it contains have already been descended into by whichever
+/// thread claimed them. Only `QUIT` is exposed, and it stops the walk once
+/// the batch that requested it has been fully delivered.
+///
+/// To prune a directory (skip `node_modules`, `.git`, ...) without
+/// descending into it, use `should_exclude_entry` instead - it runs
+/// synchronously per-entry, ahead of batching, so returning `True` from it
+/// stops the walk from ever entering that directory. This callback only
+/// gets to react after the fact.
+#[pyclass(eq, eq_int)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum WalkState {
+    Continue = 0,
+    Quit = 1,
+}
+
+// Number of entries a worker thread buffers before reacquiring the GIL to
+// run the Python callback over the whole batch at once.
+const BATCH_SIZE: usize = 256;
+
+fn flush_batch(
+    py: Python<'_>,
+    callback: &Py<PyAny>,
+    batch: &mut Vec<ignore::DirEntry>,
+    yield_entries: bool,
+) -> PyResult<IgnoreWalkState> {
+    // Every entry in the batch is unrelated to the others, so a `QUIT` from
+    // one callback call must not prevent the rest of the batch from being
+    // delivered - it only determines whether we keep walking afterwards.
+    let mut result = IgnoreWalkState::Continue;
+
+    for entry in batch.drain(..) {
+        let arg = if yield_entries {
+            Py::new(py, DirEntry::new(entry))?.into_any()
+        } else {
+            path_buf_to_pathlib_path(py, entry.path().to_path_buf())?
+        };
+
+        let state: Option<WalkState> = callback.call1(py, (arg,))?.extract(py)?;
+
+        if let Some(WalkState::Quit) = state {
+            result = IgnoreWalkState::Quit;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Per-thread batching state for `walk_parallel`. Buffers entries so the GIL
+/// is only reacquired once per `BATCH_SIZE` entries, and flushes whatever is
+/// left when the worker thread finishes its share of the walk (via `Drop`),
+/// so a short final batch is never silently dropped.
+struct BatchRunner {
+    callback: Py<PyAny>,
+    stop: Arc<AtomicBool>,
+    error: Arc<Mutex<Option<PyErr>>>,
+    batch: Vec<ignore::DirEntry>,
+    yield_entries: bool,
+}
+
+impl BatchRunner {
+    fn handle(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> IgnoreWalkState {
+        if self.stop.load(Ordering::Relaxed) {
+            return IgnoreWalkState::Quit;
+        }
+
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                *self.error.lock().unwrap() = Some(PyErr::new::<pyo3::exceptions::PyOSError, _>(
+                    format!("{}", err),
+                ));
+                self.stop.store(true, Ordering::Relaxed);
+                return IgnoreWalkState::Quit;
+            }
+        };
+
+        self.batch.push(entry);
+        if self.batch.len() < BATCH_SIZE {
+            return IgnoreWalkState::Continue;
+        }
+
+        self.flush()
+    }
+
+    fn flush(&mut self) -> IgnoreWalkState {
+        if self.batch.is_empty() {
+            return IgnoreWalkState::Continue;
+        }
+
+        match Python::attach(|py| {
+            flush_batch(py, &self.callback, &mut self.batch, self.yield_entries)
+        }) {
+            Ok(IgnoreWalkState::Continue) => IgnoreWalkState::Continue,
+            Ok(state) => {
+                self.stop.store(true, Ordering::Relaxed);
+                state
+            }
+            Err(err) => {
+                *self.error.lock().unwrap() = Some(err);
+                self.stop.store(true, Ordering::Relaxed);
+                IgnoreWalkState::Quit
+            }
+        }
+    }
+}
+
+impl Drop for BatchRunner {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Walks `path` across multiple threads, calling `callback` once per entry
+/// (entries are delivered in per-thread batches - see `WalkState`). `sort` is rejected outright since
+/// thread ordering isn't deterministic; to prune directories instead of
+/// just reacting to them, use `should_exclude_entry`, not a `WalkState`
+/// returned from `callback`.
+#[pyfunction]
+#[pyo3(signature = (
+    path,
+    callback,
+    num_threads=None,
+    ignore_hidden=None,
+    read_ignore_files=None,
+    read_parents_ignores=None,
+    read_git_ignore=None,
+    read_global_git_ignore=None,
+    read_git_exclude=None,
+    require_git=None,
+    read_hg_ignore=None,
+    additional_ignores=None,
+    additional_ignore_paths=None,
+    overrides=None,
+    types=None,
+    types_not=None,
+    add_type_definitions=None,
+    max_depth=None,
+    max_filesize=None,
+    follow_links=None,
+    case_insensitive=None,
+    same_file_system=None,
+    sort=None,
+    reverse=None,
+    path_comparator=None,
+    should_exclude_entry=None,
+    yield_entries=None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn walk_parallel(
+    py: Python<'_>,
+    path: PathBuf,
+    callback: Py<PyAny>,
+    num_threads: Option<usize>,
+
+    ignore_hidden: Option<bool>,
+
+    read_ignore_files: Option<bool>,
+    read_parents_ignores: Option<bool>,
+
+    read_git_ignore: Option<bool>,
+    read_global_git_ignore: Option<bool>,
+    read_git_exclude: Option<bool>,
+    require_git: Option<bool>,
+
+    read_hg_ignore: Option<bool>,
+
+    additional_ignores: Option<Vec<String>>,
+    additional_ignore_paths: Option<Vec<String>>,
+    overrides: Option<Vec<String>>,
+
+    types: Option<Vec<String>>,
+    types_not: Option<Vec<String>>,
+    add_type_definitions: Option<Vec<(String, Vec<String>)>>,
+
+    max_depth: Option<usize>,
+    max_filesize: Option<u64>,
+
+    follow_links: Option<bool>,
+
+    case_insensitive: Option<bool>,
+    same_file_system: Option<bool>,
+
+    sort: Option<String>,
+    reverse: Option<bool>,
+    path_comparator: Option<Py<PyAny>>,
+
+    should_exclude_entry: Option<Py<PyAny>>,
+    yield_entries: Option<bool>,
+) -> PyResult<()> {
+    if sort.is_some() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "sort is not supported by walk_parallel: entries are produced by multiple \
+             threads concurrently, so the order the callback sees them in is not \
+             deterministic even when each directory is locally sorted. Use walk()/Walker \
+             for deterministic ordering.",
+        ));
+    }
+
+    let yield_entries = yield_entries.unwrap_or(false);
+    let mut builder = build_walk_builder(
+        &path,
+        ignore_hidden,
+        read_ignore_files,
+        read_parents_ignores,
+        read_git_ignore,
+        read_global_git_ignore,
+        read_git_exclude,
+        require_git,
+        read_hg_ignore,
+        additional_ignores,
+        additional_ignore_paths,
+        overrides,
+        types,
+        types_not,
+        add_type_definitions,
+        max_depth,
+        max_filesize,
+        follow_links,
+        case_insensitive,
+        same_file_system,
+        sort,
+        reverse,
+        path_comparator,
+        should_exclude_entry,
+    )?;
+
+    if let Some(num_threads) = num_threads {
+        builder.threads(num_threads);
+    }
+
+    let parallel_walker = builder.build_parallel();
+    let stop = Arc::new(AtomicBool::new(false));
+    let error: Arc<Mutex<Option<PyErr>>> = Arc::new(Mutex::new(None));
+
+    py.detach(|| {
+        parallel_walker.run(|| {
+            let mut runner = BatchRunner {
+                callback: callback.clone(),
+                stop: Arc::clone(&stop),
+                error: Arc::clone(&error),
+                batch: Vec::with_capacity(BATCH_SIZE),
+                yield_entries,
+            };
+
+            Box::new(move |entry| runner.handle(entry))
+        });
+    });
+
+    if let Some(err) = error.lock().unwrap().take() {
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Walks `path` across multiple threads and collects every entry into a
+/// `Vec`. `sort` is rejected outright since thread ordering isn't
+/// deterministic; to prune directories rather than just filtering the
+/// collected result afterwards, use `should_exclude_entry`.
+#[pyfunction]
+#[pyo3(signature = (
+    path,
+    num_threads=None,
+    ignore_hidden=None,
+    read_ignore_files=None,
+    read_parents_ignores=None,
+    read_git_ignore=None,
+    read_global_git_ignore=None,
+    read_git_exclude=None,
+    require_git=None,
+    read_hg_ignore=None,
+    additional_ignores=None,
+    additional_ignore_paths=None,
+    overrides=None,
+    types=None,
+    types_not=None,
+    add_type_definitions=None,
+    max_depth=None,
+    max_filesize=None,
+    follow_links=None,
+    case_insensitive=None,
+    same_file_system=None,
+    sort=None,
+    reverse=None,
+    path_comparator=None,
+    should_exclude_entry=None,
+    yield_entries=None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn collect_parallel(
+    py: Python<'_>,
+    path: PathBuf,
+    num_threads: Option<usize>,
+
+    ignore_hidden: Option<bool>,
+
+    read_ignore_files: Option<bool>,
+    read_parents_ignores: Option<bool>,
+
+    read_git_ignore: Option<bool>,
+    read_global_git_ignore: Option<bool>,
+    read_git_exclude: Option<bool>,
+    require_git: Option<bool>,
+
+    read_hg_ignore: Option<bool>,
+
+    additional_ignores: Option<Vec<String>>,
+    additional_ignore_paths: Option<Vec<String>>,
+    overrides: Option<Vec<String>>,
+
+    types: Option<Vec<String>>,
+    types_not: Option<Vec<String>>,
+    add_type_definitions: Option<Vec<(String, Vec<String>)>>,
+
+    max_depth: Option<usize>,
+    max_filesize: Option<u64>,
+
+    follow_links: Option<bool>,
+
+    case_insensitive: Option<bool>,
+    same_file_system: Option<bool>,
+
+    sort: Option<String>,
+    reverse: Option<bool>,
+    path_comparator: Option<Py<PyAny>>,
+
+    should_exclude_entry: Option<Py<PyAny>>,
+    yield_entries: Option<bool>,
+) -> PyResult<Vec<Py<PyAny>>> {
+    if sort.is_some() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "sort is not supported by collect_parallel: entries are produced by multiple \
+             threads concurrently, so the resulting order is not deterministic even when \
+             each directory is locally sorted. Use walk()/Walker for deterministic ordering.",
+        ));
+    }
+
+    let yield_entries = yield_entries.unwrap_or(false);
+    let mut builder = build_walk_builder(
+        &path,
+        ignore_hidden,
+        read_ignore_files,
+        read_parents_ignores,
+        read_git_ignore,
+        read_global_git_ignore,
+        read_git_exclude,
+        require_git,
+        read_hg_ignore,
+        additional_ignores,
+        additional_ignore_paths,
+        overrides,
+        types,
+        types_not,
+        add_type_definitions,
+        max_depth,
+        max_filesize,
+        follow_links,
+        case_insensitive,
+        same_file_system,
+        sort,
+        reverse,
+        path_comparator,
+        should_exclude_entry,
+    )?;
+
+    if let Some(num_threads) = num_threads {
+        builder.threads(num_threads);
+    }
+
+    let parallel_walker = builder.build_parallel();
+    let entries: Arc<Mutex<Vec<ignore::DirEntry>>> = Arc::new(Mutex::new(Vec::new()));
+    let error: Arc<Mutex<Option<PyErr>>> = Arc::new(Mutex::new(None));
+
+    py.detach(|| {
+        parallel_walker.run(|| {
+            let entries = Arc::clone(&entries);
+            let error = Arc::clone(&error);
+
+            Box::new(move |entry| match entry {
+                Ok(entry) => {
+                    entries.lock().unwrap().push(entry);
+                    IgnoreWalkState::Continue
+                }
+                Err(err) => {
+                    *error.lock().unwrap() = Some(PyErr::new::<pyo3::exceptions::PyOSError, _>(
+                        format!("{}", err),
+                    ));
+                    IgnoreWalkState::Quit
+                }
+            })
+        });
+    });
+
+    if let Some(err) = error.lock().unwrap().take() {
+        return Err(err);
+    }
+
+    let entries = Arc::try_unwrap(entries)
+        .map(|mutex| mutex.into_inner().unwrap())
+        .unwrap_or_else(|entries| entries.lock().unwrap().drain(..).collect());
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            if yield_entries {
+                Ok(Py::new(py, DirEntry::new(entry))?.into_any())
+            } else {
+                path_buf_to_pathlib_path(py, entry.into_path())
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+
+    use super::*;
+
+    static CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    #[pyfunction]
+    fn counting_quit_callback(_entry: Py<PyAny>) -> WalkState {
+        CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+        WalkState::Quit
+    }
+
+    #[pyfunction]
+    fn counting_continue_callback(_entry: Py<PyAny>) -> WalkState {
+        CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+        WalkState::Continue
+    }
+
+    fn unique_temp_dir(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "rignore-test-{label}-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ))
+    }
+
+    fn walk_entries(dir: &std::path::Path) -> Vec<ignore::DirEntry> {
+        ignore::WalkBuilder::new(dir)
+            .build()
+            .filter_map(Result::ok)
+            .collect()
+    }
+
+    #[test]
+    fn flush_batch_delivers_every_entry_even_after_a_quit() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("flush-batch");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "").unwrap();
+        std::fs::write(dir.join("b.txt"), "").unwrap();
+        std::fs::write(dir.join("c.txt"), "").unwrap();
+
+        let mut batch = walk_entries(&dir);
+        let expected_len = batch.len();
+        assert!(expected_len >= 3);
+
+        let before = CALL_COUNT.load(Ordering::SeqCst);
+
+        let result = Python::attach(|py| {
+            let callback: Py<PyAny> = wrap_pyfunction!(counting_quit_callback, py)
+                .unwrap()
+                .into_any()
+                .unbind();
+
+            flush_batch(py, &callback, &mut batch, false).unwrap()
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(
+            CALL_COUNT.load(Ordering::SeqCst) - before,
+            expected_len,
+            "every entry in the batch must reach the callback, even after one asks to Quit"
+        );
+        assert!(matches!(result, IgnoreWalkState::Quit));
+        assert!(batch.is_empty(), "flush_batch must drain the whole batch");
+    }
+
+    #[test]
+    fn batch_runner_flushes_remaining_entries_on_drop() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("batch-runner");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "").unwrap();
+
+        // The walk yields the directory itself as well as `a.txt`.
+        let entries = walk_entries(&dir);
+        assert!(!entries.is_empty());
+        let expected_len = entries.len();
+
+        let before = CALL_COUNT.load(Ordering::SeqCst);
+
+        Python::attach(|py| {
+            let callback: Py<PyAny> = wrap_pyfunction!(counting_continue_callback, py)
+                .unwrap()
+                .into_any()
+                .unbind();
+
+            let stop = Arc::new(AtomicBool::new(false));
+            let error: Arc<Mutex<Option<PyErr>>> = Arc::new(Mutex::new(None));
+
+            {
+                let mut runner = BatchRunner {
+                    callback,
+                    stop: Arc::clone(&stop),
+                    error: Arc::clone(&error),
+                    batch: Vec::with_capacity(BATCH_SIZE),
+                    yield_entries: false,
+                };
+
+                // Far fewer entries than BATCH_SIZE, so nothing flushes
+                // until `runner` is dropped at the end of this block.
+                for entry in entries {
+                    runner.handle(Ok(entry));
+                }
+            }
+
+            assert!(error.lock().unwrap().is_none());
+        });
+
+        assert_eq!(
+            CALL_COUNT.load(Ordering::SeqCst) - before,
+            expected_len,
+            "Drop must flush a batch smaller than BATCH_SIZE instead of discarding it"
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn walk_parallel_rejects_sort() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("walk-parallel-sort");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        Python::attach(|py| {
+            let callback: Py<PyAny> = wrap_pyfunction!(counting_continue_callback, py)
+                .unwrap()
+                .into_any()
+                .unbind();
+
+            let err = walk_parallel(
+                py,
+                dir.clone(),
+                callback,
+                None, // num_threads
+                None, // ignore_hidden
+                None, // read_ignore_files
+                None, // read_parents_ignores
+                None, // read_git_ignore
+                None, // read_global_git_ignore
+                None, // read_git_exclude
+                None, // require_git
+                None, // read_hg_ignore
+                None, // additional_ignores
+                None, // additional_ignore_paths
+                None, // overrides
+                None, // types
+                None, // types_not
+                None, // add_type_definitions
+                None, // max_depth
+                None, // max_filesize
+                None, // follow_links
+                None, // case_insensitive
+                None, // same_file_system
+                Some("name".to_string()),
+                None, // reverse
+                None, // path_comparator
+                None, // should_exclude_entry
+                None, // yield_entries
+            )
+            .expect_err("walk_parallel must reject sort, not silently ignore it");
+
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn collect_parallel_rejects_sort() {
+        pyo3::prepare_freethreaded_python();
+
+        let dir = unique_temp_dir("collect-parallel-sort");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        Python::attach(|py| {
+            let err = collect_parallel(
+                py,
+                dir.clone(),
+                None, // num_threads
+                None, // ignore_hidden
+                None, // read_ignore_files
+                None, // read_parents_ignores
+                None, // read_git_ignore
+                None, // read_global_git_ignore
+                None, // read_git_exclude
+                None, // require_git
+                None, // read_hg_ignore
+                None, // additional_ignores
+                None, // additional_ignore_paths
+                None, // overrides
+                None, // types
+                None, // types_not
+                None, // add_type_definitions
+                None, // max_depth
+                None, // max_filesize
+                None, // follow_links
+                None, // case_insensitive
+                None, // same_file_system
+                Some("name".to_string()),
+                None, // reverse
+                None, // path_comparator
+                None, // should_exclude_entry
+                None, // yield_entries
+            )
+            .expect_err("collect_parallel must reject sort, not silently ignore it");
+
+            assert!(err.is_instance_of::<pyo3::exceptions::PyValueError>(py));
+        });
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}